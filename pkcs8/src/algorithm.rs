@@ -0,0 +1,52 @@
+//! X.509 `AlgorithmIdentifier`
+
+use core::convert::TryFrom;
+use der::{Any, Decodable, Encodable, Message, ObjectIdentifier};
+
+/// X.509 `AlgorithmIdentifier` as described in [RFC 5280 Section 4.1.1.2].
+///
+/// ```text
+/// AlgorithmIdentifier  ::=  SEQUENCE  {
+///      algorithm               OBJECT IDENTIFIER,
+///      parameters              ANY DEFINED BY algorithm OPTIONAL  }
+/// ```
+///
+/// [RFC 5280 Section 4.1.1.2]: https://tools.ietf.org/html/rfc5280#section-4.1.1.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AlgorithmIdentifier<'a> {
+    /// Algorithm object identifier, i.e. OID.
+    pub oid: ObjectIdentifier,
+
+    /// Algorithm `parameters`.
+    pub parameters: Option<Any<'a>>,
+}
+
+impl<'a> TryFrom<Any<'a>> for AlgorithmIdentifier<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<AlgorithmIdentifier<'a>> {
+        any.sequence(|decoder| {
+            Ok(Self {
+                oid: decoder.decode()?,
+                parameters: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for AlgorithmIdentifier<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> der::Result<AlgorithmIdentifier<'a>> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> Message<'a> for AlgorithmIdentifier<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        f(&[&self.oid, &self.parameters])
+    }
+}
@@ -0,0 +1,80 @@
+//! Error types
+
+use core::fmt;
+
+/// Result type
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// Cryptographic errors.
+    ///
+    /// This is primarily used for errors generated by the [`der::Message`]
+    /// impls in this crate, as well as the `encrypted` module.
+    #[cfg(feature = "pkcs5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs5")))]
+    Crypto,
+
+    /// [`EncryptedPrivateKeyInfo::decrypt`][`crate::EncryptedPrivateKeyInfo::decrypt`]
+    /// failed to decrypt the given ciphertext with the given password.
+    ///
+    /// This is distinct from [`Error::UnsupportedScheme`]: it means the
+    /// `encryption_algorithm` was a scheme this crate supports, but the
+    /// password was wrong (or the ciphertext was corrupt).
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    DecryptionFailed,
+
+    /// [`EncryptedPrivateKeyInfo::decrypt`][`crate::EncryptedPrivateKeyInfo::decrypt`]
+    /// was asked to decrypt an `encryption_algorithm` this crate doesn't
+    /// support (i.e. anything other than PBES2).
+    ///
+    /// This is distinct from [`Error::DecryptionFailed`]: it means decryption
+    /// was never attempted, as opposed to being attempted and failing.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    UnsupportedScheme,
+
+    /// Malformed PEM input.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    Pem(pem_rfc7468::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "ASN.1 DER error: {}", err),
+            #[cfg(feature = "pkcs5")]
+            Error::Crypto => write!(f, "cryptographic error"),
+            #[cfg(feature = "encryption")]
+            Error::DecryptionFailed => write!(f, "decryption failed (wrong password?)"),
+            #[cfg(feature = "encryption")]
+            Error::UnsupportedScheme => write!(f, "unsupported encryption scheme"),
+            #[cfg(feature = "pem")]
+            Error::Pem(err) => write!(f, "PEM error: {}", err),
+        }
+    }
+}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl From<pem_rfc7468::Error> for Error {
+    fn from(err: pem_rfc7468::Error) -> Error {
+        Error::Pem(err)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
@@ -0,0 +1,44 @@
+//! Pure Rust implementation of [PKCS#8: Private-Key Information Syntax
+//! Specification](https://tools.ietf.org/html/rfc5208), with additional
+//! support for the `OneAsymmetricKey` form described in [RFC 5958].
+//!
+//! [RFC 5958]: https://tools.ietf.org/html/rfc5958
+
+#![no_std]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod algorithm;
+#[cfg(feature = "alloc")]
+mod document;
+mod error;
+mod private_key_info;
+
+pub use crate::{
+    algorithm::AlgorithmIdentifier,
+    error::{Error, Result},
+    private_key_info::{PrivateKeyInfo, Version},
+};
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::document::PrivateKeyDocument;
+
+#[cfg(all(feature = "alloc", feature = "pkcs5"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs5"))))]
+pub use crate::document::EncryptedPrivateKeyDocument;
+
+#[cfg(feature = "pkcs5")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs5")))]
+pub use crate::private_key_info::EncryptedPrivateKeyInfo;
+
+pub use der;
+
+#[cfg(feature = "pkcs5")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs5")))]
+pub use pkcs5;
@@ -2,9 +2,32 @@
 
 use crate::{Error, Result};
 use core::convert::TryFrom;
+use core::fmt;
 use der::{Decodable, Encodable, Message};
 use pkcs5::EncryptionScheme;
 
+#[cfg(feature = "encryption")]
+use {
+    crate::{EncryptedPrivateKeyDocument, PrivateKeyDocument},
+    pkcs5::pbes2,
+    rand_core::{CryptoRng, RngCore},
+};
+
+/// Default PBKDF2 iteration count used by [`EncryptedPrivateKeyInfo::encrypt`].
+///
+/// Current OWASP guidance for PBKDF2-HMAC-SHA256 recommends at least 600,000
+/// iterations, but `pbes2::Pbkdf2Params::iteration_count` is a `u16`, so
+/// `u16::MAX` (65,535) is the most this crate can ask for. Callers who need
+/// the full OWASP-recommended iteration count (or who want scrypt's memory
+/// hardness instead) should build their own [`pbes2::Parameters`] and use
+/// [`EncryptedPrivateKeyInfo::encrypt_with_params`].
+#[cfg(feature = "encryption")]
+const PBKDF2_ITERATIONS: u16 = u16::MAX;
+
+/// Size of the randomly generated salt used by [`EncryptedPrivateKeyInfo::encrypt`].
+#[cfg(feature = "encryption")]
+const SALT_LEN: usize = 16;
+
 /// PKCS#8 `EncryptedPrivateKeyInfo`.
 ///
 /// ASN.1 structure containing a PKCS#5 [`EncryptionScheme`] identifier for a
@@ -12,13 +35,14 @@ use pkcs5::EncryptionScheme;
 ///
 /// ## Encryption algorithm support
 ///
-/// tl;dr: none yet!
+/// When the `encryption` feature is enabled, [`EncryptedPrivateKeyInfo::decrypt`]
+/// supports the PBES2 encryption scheme with the following KDF/cipher
+/// combinations:
 ///
-/// This crate does not (yet) support decrypting/encrypting private key data.
-/// However, support for the following may be added in future releases.
-/// Please see the following GitHub issue for tracking information:
+/// - KDF: PBKDF2 (any of the supported PRFs) or scrypt
+/// - Cipher: AES-128-CBC or AES-256-CBC
 ///
-/// <https://github.com/RustCrypto/utils/issues/263>
+/// PBES1 (as opposed to PBES2) is not supported.
 ///
 /// ## Schema
 /// Structure described in [RFC 5208 Section 6]:
@@ -45,11 +69,105 @@ pub struct EncryptedPrivateKeyInfo<'a> {
     pub encrypted_data: &'a [u8],
 }
 
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+impl<'a> EncryptedPrivateKeyInfo<'a> {
+    /// Attempt to decrypt this encrypted private key using the provided
+    /// password to derive an encryption key.
+    ///
+    /// Returns an owned [`PrivateKeyDocument`] rather than a borrowed
+    /// `PrivateKeyInfo`: the decrypted plaintext is freshly allocated here,
+    /// so there's no buffer with a long enough lifetime for
+    /// `PrivateKeyInfo`'s borrowed fields to point into.
+    ///
+    /// Returns [`Error::UnsupportedScheme`] if `encryption_algorithm` isn't
+    /// PBES2 (the only scheme `decrypt_in_place` implements), and
+    /// [`Error::DecryptionFailed`] if it is PBES2 but the password is
+    /// incorrect (i.e. PKCS#7 unpadding fails) or the ciphertext is corrupt.
+    /// This lets callers distinguish "wrong password" from "this crate
+    /// can't decrypt this key" without inspecting `encryption_algorithm`
+    /// themselves.
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<PrivateKeyDocument> {
+        if !matches!(self.encryption_algorithm, EncryptionScheme::Pbes2(_)) {
+            return Err(Error::UnsupportedScheme);
+        }
+
+        // Decrypt into a zeroizing buffer (when the `zeroize` feature is on)
+        // so the plaintext key material doesn't linger in this scratch copy
+        // after it's been copied into the returned `PrivateKeyDocument`.
+        let mut buffer = crate::document::secret_bytes(self.encrypted_data.to_vec());
+
+        let pt_len = self
+            .encryption_algorithm
+            .decrypt_in_place(password, &mut buffer)
+            .map_err(|_| Error::DecryptionFailed)?
+            .len();
+
+        buffer.truncate(pt_len);
+        PrivateKeyDocument::from_der(&buffer)
+    }
+
+    /// Encrypt the given DER-encoded `PrivateKeyInfo` using PBKDF2-HMAC-SHA256
+    /// (at the highest iteration count this crate's PBES2 parameters can
+    /// express, [`PBKDF2_ITERATIONS`]) and AES-256-CBC, returning the
+    /// serialized `EncryptedPrivateKeyInfo` as DER.
+    ///
+    /// A fresh salt and IV are drawn from the supplied RNG. Use
+    /// [`EncryptedPrivateKeyInfo::encrypt_with_params`] to select different
+    /// PBES2 parameters, e.g. scrypt in place of PBKDF2.
+    pub fn encrypt(
+        mut rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+        plaintext_der: &[u8],
+    ) -> Result<EncryptedPrivateKeyDocument> {
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let pbes2_params = pbes2::Parameters::pbkdf2_sha256_aes256cbc(PBKDF2_ITERATIONS, &salt, &iv)
+            .map_err(|_| Error::Crypto)?;
+
+        Self::encrypt_with_params(pbes2_params, password, plaintext_der)
+    }
+
+    /// Encrypt the given DER-encoded `PrivateKeyInfo` using a caller-supplied
+    /// set of PBES2 parameters (which embed their own salt/IV), returning the
+    /// serialized `EncryptedPrivateKeyInfo` as DER.
+    pub fn encrypt_with_params(
+        pbes2_params: pbes2::Parameters<'_>,
+        password: impl AsRef<[u8]>,
+        plaintext_der: &[u8],
+    ) -> Result<EncryptedPrivateKeyDocument> {
+        let encryption_algorithm = EncryptionScheme::Pbes2(pbes2_params);
+
+        // As with `decrypt`, keep the plaintext in a zeroizing buffer (when
+        // the `zeroize` feature is on) for as little time as possible.
+        let mut buffer = crate::document::secret_bytes(plaintext_der.to_vec());
+        buffer.extend_from_slice(&[0u8; 16]); // space for PKCS#7 padding
+
+        let ct_len = encryption_algorithm
+            .encrypt_in_place(password, &mut buffer, plaintext_der.len())
+            .map_err(|_| Error::Crypto)?
+            .len();
+        buffer.truncate(ct_len);
+
+        let der = EncryptedPrivateKeyInfo {
+            encryption_algorithm,
+            encrypted_data: &buffer,
+        }
+        .to_vec()?;
+
+        EncryptedPrivateKeyDocument::from_der(&der)
+    }
+}
+
 impl<'a> TryFrom<&'a [u8]> for EncryptedPrivateKeyInfo<'a> {
     type Error = Error;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self> {
-        Ok(Self::from_bytes(bytes)?)
+        Ok(Self::from_der(bytes)?)
     }
 }
 
@@ -66,6 +184,18 @@ impl<'a> TryFrom<der::Any<'a>> for EncryptedPrivateKeyInfo<'a> {
     }
 }
 
+impl<'a> fmt::Debug for EncryptedPrivateKeyInfo<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `encrypted_data` is deliberately omitted: even ciphertext is worth
+        // keeping out of logs, since it reveals the private key's length and
+        // could aid an attacker who later recovers the password.
+        f.debug_struct("EncryptedPrivateKeyInfo")
+            .field("encryption_algorithm", &self.encryption_algorithm)
+            .field("encrypted_data", &"...")
+            .finish()
+    }
+}
+
 impl<'a> Message<'a> for EncryptedPrivateKeyInfo<'a> {
     fn fields<F, T>(&self, f: F) -> der::Result<T>
     where
@@ -76,4 +206,4 @@ impl<'a> Message<'a> for EncryptedPrivateKeyInfo<'a> {
             &der::OctetString::new(self.encrypted_data)?,
         ])
     }
-}
\ No newline at end of file
+}
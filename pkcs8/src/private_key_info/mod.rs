@@ -0,0 +1,163 @@
+//! PKCS#8 `PrivateKeyInfo`
+
+#[cfg(feature = "pkcs5")]
+pub mod encrypted;
+
+use crate::{AlgorithmIdentifier, Result};
+use core::convert::TryFrom;
+use der::{Decodable, Encodable, Message};
+
+#[cfg(feature = "pkcs5")]
+pub use self::encrypted::EncryptedPrivateKeyInfo;
+
+/// Context-specific tag number for the `publicKey` field of `OneAsymmetricKey`
+/// as defined by [RFC 5958 Section 2].
+///
+/// [RFC 5958 Section 2]: https://tools.ietf.org/html/rfc5958#section-2
+const PUBLIC_KEY_TAG: u8 = 1;
+
+/// PKCS#8 `Version` as enumerated in [RFC 5958 Section 2]:
+///
+/// ```text
+/// Version ::= INTEGER { v1(0), v2(1) } (v1, ..., v2)
+/// ```
+///
+/// RFC 5958 permits a v2-tagged key with no `publicKey` field present, so
+/// this can't be derived from whether `public_key` is `Some`; it's tracked
+/// as its own field on [`PrivateKeyInfo`] so a key decoded as v2 round-trips
+/// back to v2 even when its public key happens to be absent.
+///
+/// [RFC 5958 Section 2]: https://tools.ietf.org/html/rfc5958#section-2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Version {
+    /// Denotes PKCS#8 v1: no public key field.
+    V1,
+
+    /// Denotes PKCS#8 v2: may include a public key field.
+    V2,
+}
+
+impl Version {
+    /// Is this version 1?
+    pub fn is_v1(self) -> bool {
+        self == Version::V1
+    }
+
+    /// Is this version 2?
+    pub fn is_v2(self) -> bool {
+        self == Version::V2
+    }
+}
+
+/// PKCS#8 `PrivateKeyInfo`.
+///
+/// ASN.1 structure containing an `AlgorithmIdentifier` and private key
+/// data in an algorithm specific format. Supports the `OneAsymmetricKey`
+/// form described in [RFC 5958 Section 2], which extends the original
+/// [RFC 5208 Section 5] `PrivateKeyInfo` with an optional public key for
+/// algorithms (e.g. Ed25519) which bundle it alongside the private key.
+///
+/// ```text
+/// OneAsymmetricKey ::= SEQUENCE {
+///   version                   Version,
+///   privateKeyAlgorithm       PrivateKeyAlgorithmIdentifier,
+///   privateKey                PrivateKey,
+///   attributes            [0] Attributes OPTIONAL,
+///   ...,
+///   [[2: publicKey       [1] PublicKey OPTIONAL ]],
+///   ...}
+///
+/// Version ::= INTEGER { v1(0), v2(1) } (v1, ..., v2)
+///
+/// PrivateKeyAlgorithmIdentifier ::= AlgorithmIdentifier
+///
+/// PrivateKey ::= OCTET STRING
+///
+/// PublicKey ::= BIT STRING
+/// ```
+///
+/// This implementation does not support the `attributes` field, which is
+/// unused in practice.
+///
+/// [RFC 5958 Section 2]: https://tools.ietf.org/html/rfc5958#section-2
+/// [RFC 5208 Section 5]: https://tools.ietf.org/html/rfc5208#section-5
+#[derive(Clone)]
+pub struct PrivateKeyInfo<'a> {
+    /// PKCS#8 [`Version`] of this key, as decoded from the ASN.1 `Version`
+    /// field.
+    pub version: Version,
+
+    /// X.509 `AlgorithmIdentifier` for the private key type.
+    pub algorithm: AlgorithmIdentifier<'a>,
+
+    /// Private key data.
+    pub private_key: &'a [u8],
+
+    /// Public key data, if present.
+    ///
+    /// This is only encoded/decoded for the PKCS#8 v2 `OneAsymmetricKey`
+    /// form; see [RFC 5958 Section 2].
+    pub public_key: Option<&'a [u8]>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for PrivateKeyInfo<'a> {
+    type Error = crate::Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl<'a> TryFrom<der::Any<'a>> for PrivateKeyInfo<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: der::Any<'a>) -> der::Result<PrivateKeyInfo<'a>> {
+        any.sequence(|decoder| {
+            let version = match decoder.decode::<u8>()? {
+                0 => Version::V1,
+                _ => Version::V2,
+            };
+
+            let algorithm = decoder.decode()?;
+            let private_key = decoder.octet_string()?.as_bytes();
+
+            let public_key = if version.is_v2() {
+                decoder
+                    .context_specific_optional()?
+                    .filter(|field| field.tag() == PUBLIC_KEY_TAG)
+                    .map(|field| field.value().bit_string())
+                    .transpose()?
+                    .map(|bs| bs.as_bytes())
+            } else {
+                None
+            };
+
+            Ok(Self {
+                version,
+                algorithm,
+                private_key,
+                public_key,
+            })
+        })
+    }
+}
+
+impl<'a> Message<'a> for PrivateKeyInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        let version = self.version as u8;
+        let private_key = der::OctetString::new(self.private_key)?;
+
+        match self.public_key {
+            Some(public_key) => {
+                let public_key =
+                    der::ContextSpecific::new(PUBLIC_KEY_TAG, der::BitString::new(public_key)?.into())?;
+
+                f(&[&version, &self.algorithm, &private_key, &public_key])
+            }
+            None => f(&[&version, &self.algorithm, &private_key]),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Encrypt/decrypt round-trip tests for `EncryptedPrivateKeyInfo`.
+//!
+//! Fixtures under `tests/examples/` were generated with OpenSSL, e.g.:
+//!
+//! ```text
+//! openssl pkcs8 -topk8 -in rsa512-priv.pem -out pbes2-pbkdf2-sha256-aes256cbc.pem \
+//!     -v2 aes-256-cbc -v2prf hmacWithSHA256 -passout pass:hunter2
+//! openssl pkcs8 -topk8 -in rsa512-priv.pem -out pbes2-scrypt-aes256cbc.pem \
+//!     -v2 aes-256-cbc -scrypt -passout pass:hunter2
+//! openssl pkcs8 -topk8 -in rsa512-priv.pem -out pbes1-md5-des-cbc.pem \
+//!     -v1 PBE-MD5-DES -passout pass:hunter2 -provider legacy -provider default
+//! ```
+
+#![cfg(feature = "encryption")]
+
+use pkcs8::{EncryptedPrivateKeyDocument, Error, PrivateKeyDocument};
+
+const PBKDF2_SHA256_AES256CBC_PEM: &str =
+    include_str!("examples/pbes2-pbkdf2-sha256-aes256cbc.pem");
+const SCRYPT_AES256CBC_PEM: &str = include_str!("examples/pbes2-scrypt-aes256cbc.pem");
+const PBES1_MD5_DES_CBC_PEM: &str = include_str!("examples/pbes1-md5-des-cbc.pem");
+const PLAINTEXT_PEM: &str = include_str!("examples/rsa512-priv.pem");
+
+const PASSWORD: &[u8] = b"hunter2";
+
+fn decode_pem(label: &str, pem: &str) -> Vec<u8> {
+    let (actual_label, der) = pem_rfc7468::decode_vec(pem.as_bytes()).expect("malformed test fixture");
+    assert_eq!(actual_label, label, "unexpected PEM label in test fixture");
+    der
+}
+
+fn plaintext_der() -> Vec<u8> {
+    decode_pem("PRIVATE KEY", PLAINTEXT_PEM)
+}
+
+#[test]
+fn decrypt_pbkdf2_hmac_sha256_aes256cbc() {
+    let der = decode_pem("ENCRYPTED PRIVATE KEY", PBKDF2_SHA256_AES256CBC_PEM);
+    let doc = EncryptedPrivateKeyDocument::from_der(&der).unwrap();
+    let decrypted = doc.decrypt(PASSWORD).unwrap();
+    assert_eq!(decrypted.to_der(), plaintext_der().as_slice());
+}
+
+#[test]
+fn decrypt_scrypt_aes256cbc() {
+    let der = decode_pem("ENCRYPTED PRIVATE KEY", SCRYPT_AES256CBC_PEM);
+    let doc = EncryptedPrivateKeyDocument::from_der(&der).unwrap();
+    let decrypted = doc.decrypt(PASSWORD).unwrap();
+    assert_eq!(decrypted.to_der(), plaintext_der().as_slice());
+}
+
+#[test]
+fn decrypt_with_wrong_password_fails() {
+    let der = decode_pem("ENCRYPTED PRIVATE KEY", PBKDF2_SHA256_AES256CBC_PEM);
+    let doc = EncryptedPrivateKeyDocument::from_der(&der).unwrap();
+    match doc.decrypt(b"not the password") {
+        Err(Error::DecryptionFailed) => (),
+        other => panic!("expected Error::DecryptionFailed, got {:?}", other.map(drop)),
+    }
+}
+
+#[test]
+fn decrypt_with_unsupported_scheme_fails() {
+    let der = decode_pem("ENCRYPTED PRIVATE KEY", PBES1_MD5_DES_CBC_PEM);
+    let doc = EncryptedPrivateKeyDocument::from_der(&der).unwrap();
+    match doc.decrypt(PASSWORD) {
+        Err(Error::UnsupportedScheme) => (),
+        other => panic!("expected Error::UnsupportedScheme, got {:?}", other.map(drop)),
+    }
+}
+
+#[test]
+fn encrypt_then_decrypt_round_trips() {
+    let plaintext = PrivateKeyDocument::from_der(&plaintext_der()).unwrap();
+    let encrypted = plaintext.encrypt(rand::thread_rng(), PASSWORD).unwrap();
+    let decrypted = encrypted.decrypt(PASSWORD).unwrap();
+    assert_eq!(decrypted.to_der(), plaintext.to_der());
+}
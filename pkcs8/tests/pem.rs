@@ -0,0 +1,21 @@
+//! PEM (RFC 7468) round-trip tests for `EncryptedPrivateKeyDocument`.
+
+#![cfg(feature = "pem")]
+
+use pkcs8::EncryptedPrivateKeyDocument;
+
+const PBKDF2_SHA256_AES256CBC_PEM: &str =
+    include_str!("examples/pbes2-pbkdf2-sha256-aes256cbc.pem");
+
+#[test]
+fn from_pem_then_to_pem_round_trips() {
+    let doc = EncryptedPrivateKeyDocument::from_pem(PBKDF2_SHA256_AES256CBC_PEM).unwrap();
+    let pem = doc.to_pem(pem_rfc7468::LineEnding::LF).unwrap();
+    assert_eq!(pem, PBKDF2_SHA256_AES256CBC_PEM);
+}
+
+#[test]
+fn from_pem_rejects_wrong_label() {
+    let not_a_key = "-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----\n";
+    assert!(EncryptedPrivateKeyDocument::from_pem(not_a_key).is_err());
+}
@@ -0,0 +1,27 @@
+//! Decoding tests for `PrivateKeyInfo`, covering both the PKCS#8 v1
+//! (RFC 5208) and v2 `OneAsymmetricKey` (RFC 5958) forms.
+//!
+//! `ed25519-v2.der` was hand-assembled from a real OpenSSL-generated
+//! Ed25519 keypair (private key + its `SubjectPublicKeyInfo`) to exercise
+//! the v2 `[1] publicKey` field, since OpenSSL itself only emits the v1
+//! form for this algorithm.
+
+use core::convert::TryFrom;
+use pkcs8::{PrivateKeyInfo, Version};
+
+const RSA_V1_DER: &[u8] = include_bytes!("examples/rsa512-priv.der");
+const ED25519_V2_DER: &[u8] = include_bytes!("examples/ed25519-v2.der");
+
+#[test]
+fn decodes_v1_with_no_public_key() {
+    let key = PrivateKeyInfo::try_from(RSA_V1_DER).unwrap();
+    assert_eq!(key.version, Version::V1);
+    assert_eq!(key.public_key, None);
+}
+
+#[test]
+fn decodes_v2_with_public_key() {
+    let key = PrivateKeyInfo::try_from(ED25519_V2_DER).unwrap();
+    assert_eq!(key.version, Version::V2);
+    assert_eq!(key.public_key.unwrap().len(), 32); // raw point, sans unused-bits byte
+}